@@ -38,12 +38,140 @@ impl fmt::Display for OutOfRangeError {
     }
 }
 
+/// Struct represents a custom error that should be raised every time
+/// a user tries to build a bitarray out of a byte buffer whose length
+/// does not match the requested bitarray size.
+#[derive(Clone, Debug)]
+pub struct InvalidByteLengthError {
+    pub bitarray_size: i64,
+    pub expected_bytes_count: usize,
+    pub given_bytes_count: usize,
+}
+
+impl InvalidByteLengthError {
+    /// Constructor used to initialize a new InvalidByteLengthError with a given bitarray_size,
+    /// expected_bytes_count and given_bytes_count.
+    /// "bitarray_size" - The size in bits of the bitarray being built.
+    /// "expected_bytes_count" - The number of bytes required to back a bitarray of this size.
+    /// "given_bytes_count" - The number of bytes actually provided.
+    pub fn new(bitarray_size: i64, expected_bytes_count: usize, given_bytes_count: usize) -> Self {
+        Self {
+            bitarray_size,
+            expected_bytes_count,
+            given_bytes_count,
+        }
+    }
+}
+
+impl fmt::Display for InvalidByteLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Given {} bytes cannot back a bitarray of size {}, expected {} bytes.",
+            self.given_bytes_count, self.bitarray_size, self.expected_bytes_count
+        )
+    }
+}
+
+/// Struct represents a custom error that should be raised every time
+/// a user tries to combine two bitarrays of different sizes.
+#[derive(Clone, Debug)]
+pub struct SizeMismatchError {
+    pub left_size: i64,
+    pub right_size: i64,
+}
+
+impl SizeMismatchError {
+    /// Constructor used to initialize a new SizeMismatchError with a given left_size and right_size.
+    /// "left_size" - The size in bits of the bitarray on the left-hand side of the operation.
+    /// "right_size" - The size in bits of the bitarray on the right-hand side of the operation.
+    pub fn new(left_size: i64, right_size: i64) -> Self {
+        Self {
+            left_size,
+            right_size,
+        }
+    }
+}
+
+impl fmt::Display for SizeMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Cannot combine bitarrays of different sizes: {} and {}.",
+            self.left_size, self.right_size
+        )
+    }
+}
+
+/// Struct represents a custom error that should be raised every time
+/// a user tries to combine two bitarrays built with different bit orders.
+/// Combining raw bytes from bitarrays with different "BitOrder"s would silently
+/// mix up logical bit positions, so this is rejected instead.
+#[derive(Clone, Debug)]
+pub struct OrderMismatchError {
+    pub left_order: BitOrder,
+    pub right_order: BitOrder,
+}
+
+impl OrderMismatchError {
+    /// Constructor used to initialize a new OrderMismatchError with a given left_order and right_order.
+    /// "left_order" - The BitOrder of the bitarray on the left-hand side of the operation.
+    /// "right_order" - The BitOrder of the bitarray on the right-hand side of the operation.
+    pub fn new(left_order: BitOrder, right_order: BitOrder) -> Self {
+        Self {
+            left_order,
+            right_order,
+        }
+    }
+}
+
+impl fmt::Display for OrderMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Cannot combine bitarrays of different bit orders: {:?} and {:?}.",
+            self.left_order, self.right_order
+        )
+    }
+}
+
+/// Struct represents the error raised by the bitwise combinators ("and"/"or"/"xor")
+/// when the two bitarrays being combined are not compatible.
+#[derive(Clone, Debug)]
+pub enum CombineError {
+    SizeMismatch(SizeMismatchError),
+    OrderMismatch(OrderMismatchError),
+}
+
+impl fmt::Display for CombineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CombineError::SizeMismatch(err) => err.fmt(f),
+            CombineError::OrderMismatch(err) => err.fmt(f),
+        }
+    }
+}
+
+/// Describes how bit positions map onto the bits of each byte in the backing vector.
+/// "Lsb0" - Position 0 of a byte is its least significant bit, i.e. the mask is
+///          `1 << (position % 8)`. This is the historical behaviour of this crate.
+/// "Msb0" - Position 0 of a byte is its most significant bit, i.e. the mask is
+///          `1 << (7 - (position % 8))`. Use this to interoperate with bytes produced
+///          by systems that index bits from the high end of each byte (e.g. network-order
+///          bitfields).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BitOrder {
+    Lsb0,
+    Msb0,
+}
+
 /// A structure aimed to bring the bitarray functionality.
-/// Structure is described within two fields.
+/// Structure is described within three fields.
 /// "size" - The number of bits that will be allocated during the
 ///          struct instance initialization.
 /// "bit_array" - The vector of 8 bit integer used to represent bits
 ///               where each 8 bits are packed in every 8 bit integer.
+/// "order" - The BitOrder used to map a bit position onto a mask within its byte.
 /// ```rust
 /// use bitarray_naive::BitArray;
 ///
@@ -59,13 +187,24 @@ impl fmt::Display for OutOfRangeError {
 pub struct BitArray {
     pub size: i64,
     pub bit_array: Vec<u8>,
+    pub order: BitOrder,
 }
 
 impl BitArray {
     /// Constructor used to initialize a new instance of the bitarray with a given size.
+    /// Uses the default "Lsb0" bit order.
     /// "size" - The number of bits that will be allocated during the
     ///          struct instance initialization.
     pub fn new(size: i64) -> Self {
+        Self::with_order(size, BitOrder::Lsb0)
+    }
+
+    /// Constructor used to initialize a new instance of the bitarray with a given size
+    /// and a given bit order.
+    /// "size" - The number of bits that will be allocated during the
+    ///          struct instance initialization.
+    /// "order" - The BitOrder used to map a bit position onto a mask within its byte.
+    pub fn with_order(size: i64, order: BitOrder) -> Self {
         // Calculates the number of elements should be allocated in vector per given size
         let _capacity: usize = (size / ONE_BYTE_BITS_COUNT as i64) as usize + 1;
 
@@ -76,7 +215,59 @@ impl BitArray {
             bit_array.push(0);
         }
 
-        Self { size, bit_array }
+        Self {
+            size,
+            bit_array,
+            order,
+        }
+    }
+
+    /// Builds a bitarray of a given size directly from a raw byte buffer, using the default
+    /// "Lsb0" bit order. The buffer must hold exactly as many bytes as `BitArray::new(size)`
+    /// would allocate, otherwise an "InvalidByteLengthError" is raised.
+    /// "size" - The number of bits the resulting bitarray will report.
+    /// "bytes" - The raw bytes to use as the backing storage.
+    pub fn from_bytes(size: i64, bytes: &[u8]) -> Result<Self, InvalidByteLengthError> {
+        let _capacity: usize = (size / ONE_BYTE_BITS_COUNT as i64) as usize + 1;
+
+        if bytes.len() != _capacity {
+            return Err(InvalidByteLengthError::new(size, _capacity, bytes.len()));
+        }
+
+        let mut bitarray: Self = Self {
+            size,
+            bit_array: bytes.to_vec(),
+            order: BitOrder::Lsb0,
+        };
+
+        bitarray.fix_last_byte();
+
+        Ok(bitarray)
+    }
+
+    /// Returns the raw bytes backing this bitarray, with any trailing padding bits beyond
+    /// "size" in the final byte forced to zero.
+    pub fn to_bytes(&self) -> &[u8] {
+        &self.bit_array
+    }
+
+    /// Forces the bits in the last byte of the backing vector that fall beyond "size" to zero,
+    /// so the backing storage stays safe to compare, hash, and bulk-scan.
+    fn fix_last_byte(&mut self) {
+        let _last_byte_bits: i64 =
+            self.size - ONE_BYTE_BITS_COUNT as i64 * (self.bit_array.len() as i64 - 1);
+
+        let _mask: u16 = if _last_byte_bits >= ONE_BYTE_BITS_COUNT as i64 {
+            0xFF
+        } else if _last_byte_bits <= 0 {
+            0
+        } else {
+            (1u16 << _last_byte_bits) - 1
+        };
+
+        if let Some(last) = self.bit_array.last_mut() {
+            *last &= _mask as u8;
+        }
     }
 
     /// Calculates the element in bit array vector should be picked per given bit position.
@@ -85,21 +276,24 @@ impl BitArray {
         (position / ONE_BYTE_BITS_COUNT as i64) as usize
     }
 
-    /// Calculates the bit offset (position) in a given 8 bit integer.
-    /// The position should be counted from right to left.
-    fn calc_byte_offset(position: i64) -> u8 {
+    /// Calculates the bit offset (position) in a given 8 bit integer, honouring "self.order".
+    /// With "Lsb0" the position is counted from right to left, with "Msb0" from left to right.
+    fn calc_byte_offset(&self, position: i64) -> u8 {
         let _pow: i64 = position % ONE_BYTE_BITS_COUNT as i64;
 
-        2u64.pow(_pow as u32) as u8
+        match self.order {
+            BitOrder::Lsb0 => 2u64.pow(_pow as u32) as u8,
+            BitOrder::Msb0 => 2u64.pow((ONE_BYTE_BITS_COUNT as i64 - 1 - _pow) as u32) as u8,
+        }
     }
 
     /// Sets either true or false value in bit array at given position.
     pub fn set(&mut self, position: i64, flag: bool) -> Result<(), OutOfRangeError> {
-        if position >= self.size {
+        if position < 0 || position >= self.size {
             Err(OutOfRangeError::new(self.size, position as i64))
         } else {
             let vec_position: usize = Self::calc_vec_position(position);
-            let byte_offset: u8 = Self::calc_byte_offset(position);
+            let byte_offset: u8 = self.calc_byte_offset(position);
 
             if flag {
                 self.bit_array[vec_position] |= byte_offset;
@@ -113,20 +307,311 @@ impl BitArray {
 
     /// Gets either true or false value in bit array at given position.
     pub fn get(&self, position: i64) -> Result<bool, OutOfRangeError> {
-        if position >= self.size {
+        if position < 0 || position >= self.size {
             Err(OutOfRangeError::new(self.size, position))
         } else {
             let vec_position: usize = Self::calc_vec_position(position);
-            let byte_offset: u8 = Self::calc_byte_offset(position);
+            let byte_offset: u8 = self.calc_byte_offset(position);
 
             Ok(self.bit_array[vec_position] == (self.bit_array[vec_position] | byte_offset))
         }
     }
+
+    /// Sets a multi-bit integer value starting at "bit_offset" and spanning "bit_width" bits.
+    /// "bit_offset" - The position of the first (least significant) bit to write.
+    /// "bit_width" - The number of bits to write, at most 64.
+    /// "value" - The integer value whose lowest "bit_width" bits are written into the array.
+    pub fn set_range(
+        &mut self,
+        bit_offset: i64,
+        bit_width: u8,
+        value: u64,
+    ) -> Result<(), OutOfRangeError> {
+        if bit_offset < 0 || bit_width > 64 || bit_offset > self.size - bit_width as i64 {
+            return Err(OutOfRangeError::new(
+                self.size,
+                bit_offset.saturating_add(bit_width as i64),
+            ));
+        }
+
+        for i in 0..bit_width as i64 {
+            self.set(bit_offset + i, (value >> i) & 1 != 0)?;
+        }
+
+        Ok(())
+    }
+
+    /// Gets a multi-bit integer value starting at "bit_offset" and spanning "bit_width" bits.
+    /// "bit_offset" - The position of the first (least significant) bit to read.
+    /// "bit_width" - The number of bits to read, at most 64.
+    pub fn get_range(&self, bit_offset: i64, bit_width: u8) -> Result<u64, OutOfRangeError> {
+        if bit_offset < 0 || bit_width > 64 || bit_offset > self.size - bit_width as i64 {
+            return Err(OutOfRangeError::new(
+                self.size,
+                bit_offset.saturating_add(bit_width as i64),
+            ));
+        }
+
+        let mut value: u64 = 0;
+
+        for i in 0..bit_width as i64 {
+            let bit: bool = self.get(bit_offset + i)?;
+            value |= (bit as u64) << i;
+        }
+
+        Ok(value)
+    }
+
+    /// Returns an iterator over the positions of every bit currently set to true, in
+    /// ascending position order. Walks the backing vector byte by byte and, for each
+    /// non-zero byte, repeatedly extracts the logically-first remaining set bit instead
+    /// of probing every position through "get": under "Lsb0" that is the lowest raw bit,
+    /// under "Msb0" it is the highest raw bit.
+    pub fn iter_ones(&self) -> impl Iterator<Item = i64> + '_ {
+        let size: i64 = self.size;
+        let order: BitOrder = self.order;
+
+        self.bit_array
+            .iter()
+            .enumerate()
+            .flat_map(move |(byte_index, &byte)| {
+                let mut remaining: u8 = byte;
+
+                std::iter::from_fn(move || {
+                    if remaining == 0 {
+                        return None;
+                    }
+
+                    let (extracted, bit): (u8, u32) = match order {
+                        BitOrder::Lsb0 => {
+                            let lowest: u8 = remaining & remaining.wrapping_neg();
+                            (lowest, lowest.trailing_zeros())
+                        }
+                        BitOrder::Msb0 => {
+                            let highest: u8 = 1u8 << (7 - remaining.leading_zeros());
+                            (highest, highest.trailing_zeros())
+                        }
+                    };
+
+                    remaining ^= extracted;
+
+                    let bit_in_byte: i64 = match order {
+                        BitOrder::Lsb0 => bit as i64,
+                        BitOrder::Msb0 => ONE_BYTE_BITS_COUNT as i64 - 1 - bit as i64,
+                    };
+
+                    Some(byte_index as i64 * ONE_BYTE_BITS_COUNT as i64 + bit_in_byte)
+                })
+            })
+            .filter(move |&position| position < size)
+    }
+
+    /// Returns an iterator over every bit in the bitarray, in position order.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.size).map(move |position| self.get(position).unwrap())
+    }
+
+    /// Sets every in-range bit of the bitarray to true.
+    pub fn fill(&mut self) {
+        for byte in self.bit_array.iter_mut() {
+            *byte = 0xFF;
+        }
+
+        self.fix_last_byte();
+    }
+
+    /// Sets every bit of the bitarray to false.
+    pub fn clear(&mut self) {
+        for byte in self.bit_array.iter_mut() {
+            *byte = 0;
+        }
+    }
+
+    /// Counts the number of bits currently set to true in the bitarray.
+    pub fn count_ones(&self) -> u64 {
+        let mut masked: Self = Self {
+            size: self.size,
+            bit_array: self.bit_array.clone(),
+            order: self.order,
+        };
+
+        masked.fix_last_byte();
+
+        masked
+            .bit_array
+            .iter()
+            .map(|byte| byte.count_ones() as u64)
+            .sum()
+    }
+
+    /// Checks that "self" and "other" are of the same size and bit order, returning a
+    /// "CombineError" otherwise.
+    fn check_compatible(&self, other: &Self) -> Result<(), CombineError> {
+        if self.size != other.size {
+            Err(CombineError::SizeMismatch(SizeMismatchError::new(
+                self.size, other.size,
+            )))
+        } else if self.order != other.order {
+            Err(CombineError::OrderMismatch(OrderMismatchError::new(
+                self.order,
+                other.order,
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Combines "self" and "other" into a new bitarray of the same size, applying "op" byte by byte.
+    fn combine_with(&self, other: &Self, op: impl Fn(u8, u8) -> u8) -> Result<Self, CombineError> {
+        self.check_compatible(other)?;
+
+        let bit_array: Vec<u8> = self
+            .bit_array
+            .iter()
+            .zip(other.bit_array.iter())
+            .map(|(&left, &right)| op(left, right))
+            .collect();
+
+        let mut result: Self = Self {
+            size: self.size,
+            bit_array,
+            order: self.order,
+        };
+
+        result.fix_last_byte();
+
+        Ok(result)
+    }
+
+    /// Combines "self" and "other" into a new bitarray holding the bitwise AND of both.
+    pub fn and(&self, other: &Self) -> Result<Self, CombineError> {
+        self.combine_with(other, |left, right| left & right)
+    }
+
+    /// Combines "self" and "other" into a new bitarray holding the bitwise OR of both.
+    pub fn or(&self, other: &Self) -> Result<Self, CombineError> {
+        self.combine_with(other, |left, right| left | right)
+    }
+
+    /// Combines "self" and "other" into a new bitarray holding the bitwise XOR of both.
+    pub fn xor(&self, other: &Self) -> Result<Self, CombineError> {
+        self.combine_with(other, |left, right| left ^ right)
+    }
+
+    /// Builds a new bitarray holding the bitwise NOT of "self".
+    pub fn not(&self) -> Self {
+        let bit_array: Vec<u8> = self.bit_array.iter().map(|&byte| !byte).collect();
+
+        let mut result: Self = Self {
+            size: self.size,
+            bit_array,
+            order: self.order,
+        };
+
+        result.fix_last_byte();
+
+        result
+    }
+}
+
+/// A sequential cursor built on top of a "BitArray", used to serialize and deserialize
+/// packed wire formats (e.g. PER/UPER-style encodings). Fields are pushed in order with
+/// "append_bit"/"append_bits" and can later be read back in the same order with "read_bit",
+/// independently of how far writing has progressed.
+/// "bitarray" - The backing storage, grown as needed while writing.
+/// "write_position" - The position the next appended bit will be written to.
+/// "read_position" - The position the next "read_bit" call will read from.
+pub struct BitCursor {
+    pub bitarray: BitArray,
+    pub write_position: i64,
+    pub read_position: i64,
+}
+
+impl BitCursor {
+    /// Constructor used to initialize a new, empty bit cursor with the default "Lsb0" bit order.
+    pub fn new() -> Self {
+        Self::with_order(BitOrder::Lsb0)
+    }
+
+    /// Constructor used to initialize a new, empty bit cursor with a given bit order.
+    /// "order" - The BitOrder used by the backing bitarray.
+    pub fn with_order(order: BitOrder) -> Self {
+        Self {
+            bitarray: BitArray::with_order(0, order),
+            write_position: 0,
+            read_position: 0,
+        }
+    }
+
+    /// Grows the backing bitarray, if needed, so it can hold up to "required_size" bits,
+    /// keeping "bitarray.size" in sync with "write_position" as bits are appended.
+    fn ensure_capacity(&mut self, required_size: i64) {
+        let _capacity: usize = (required_size / ONE_BYTE_BITS_COUNT as i64) as usize + 1;
+
+        while self.bitarray.bit_array.len() < _capacity {
+            self.bitarray.bit_array.push(0);
+        }
+
+        if required_size > self.bitarray.size {
+            self.bitarray.size = required_size;
+        }
+    }
+
+    /// Appends a single bit at the end of the cursor, growing the backing bitarray if needed.
+    pub fn append_bit(&mut self, bit: bool) {
+        self.ensure_capacity(self.write_position + 1);
+
+        self.bitarray.set(self.write_position, bit).unwrap();
+
+        self.write_position += 1;
+    }
+
+    /// Appends the lowest "count" bits of "value" at the end of the cursor, growing the
+    /// backing bitarray if needed.
+    /// "value" - The integer value whose lowest "count" bits are appended.
+    /// "count" - The number of bits to append, at most 64.
+    pub fn append_bits(&mut self, value: u64, count: u8) {
+        assert!(count <= 64, "bit count {} exceeds 64", count);
+
+        self.ensure_capacity(self.write_position + count as i64);
+
+        self.bitarray
+            .set_range(self.write_position, count, value)
+            .unwrap();
+
+        self.write_position += count as i64;
+    }
+
+    /// Reads the next bit at the current read position and advances it, or returns "None"
+    /// once the read position catches up with everything written so far.
+    pub fn read_bit(&mut self) -> Option<bool> {
+        if self.read_position >= self.write_position {
+            return None;
+        }
+
+        let bit: bool = self.bitarray.get(self.read_position).unwrap();
+
+        self.read_position += 1;
+
+        Some(bit)
+    }
+
+    /// Rewinds the read position back to the start of the cursor, without touching what
+    /// has been written so far.
+    pub fn reset_read_position(&mut self) {
+        self.read_position = 0;
+    }
+}
+
+impl Default for BitCursor {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{BitArray, ONE_BYTE_BITS_COUNT};
+    use super::{BitArray, BitCursor, BitOrder, CombineError, ONE_BYTE_BITS_COUNT};
 
     #[test]
     fn test_init_bitarray() {
@@ -229,4 +714,284 @@ mod tests {
             assert!(!bitarray.get(bitarray_position).unwrap());
         }
     }
+
+    #[test]
+    fn test_bitarray_set_range_get_range() {
+        let bitarray_size: i64 = 64;
+
+        let mut bitarray: BitArray = BitArray::new(bitarray_size);
+
+        bitarray.set_range(8, 16, 0xBEEF).unwrap();
+
+        assert_eq!(bitarray.get_range(8, 16).unwrap(), 0xBEEF);
+    }
+
+    #[test]
+    fn test_bitarray_set_range_with_error() {
+        let bitarray_size: i64 = 10;
+
+        let mut bitarray: BitArray = BitArray::new(bitarray_size);
+
+        let success: bool = match bitarray.set_range(8, 4, 0) {
+            Ok(_) => false,
+            Err(err) => err.bitarray_size == 10 && err.bitarray_position == 12,
+        };
+
+        assert!(success);
+    }
+
+    #[test]
+    fn test_bitarray_get_range_with_error() {
+        let bitarray_size: i64 = 10;
+
+        let bitarray: BitArray = BitArray::new(bitarray_size);
+
+        let success: bool = match bitarray.get_range(8, 4) {
+            Ok(_) => false,
+            Err(err) => err.bitarray_size == 10 && err.bitarray_position == 12,
+        };
+
+        assert!(success);
+    }
+
+    #[test]
+    fn test_bitarray_get_range_with_negative_offset() {
+        let bitarray_size: i64 = 10;
+
+        let bitarray: BitArray = BitArray::new(bitarray_size);
+
+        let success: bool = match bitarray.get_range(-2, 4) {
+            Ok(_) => false,
+            Err(err) => err.bitarray_size == 10,
+        };
+
+        assert!(success);
+    }
+
+    #[test]
+    fn test_bitarray_set_range_with_negative_offset() {
+        let bitarray_size: i64 = 10;
+
+        let mut bitarray: BitArray = BitArray::new(bitarray_size);
+
+        let success: bool = match bitarray.set_range(-2, 4, 0) {
+            Ok(_) => false,
+            Err(err) => err.bitarray_size == 10,
+        };
+
+        assert!(success);
+    }
+
+    #[test]
+    fn test_bitarray_get_range_set_range_with_huge_offset() {
+        let bitarray_size: i64 = 10;
+
+        let bitarray: BitArray = BitArray::new(bitarray_size);
+        let mut mutable_bitarray: BitArray = BitArray::new(bitarray_size);
+
+        assert!(bitarray.get_range(i64::MAX - 2, 10).is_err());
+        assert!(mutable_bitarray.set_range(i64::MAX - 2, 10, 0).is_err());
+    }
+
+    #[test]
+    fn test_bitarray_get_set_with_negative_position() {
+        let bitarray_size: i64 = 10;
+
+        let mut bitarray: BitArray = BitArray::new(bitarray_size);
+
+        assert!(bitarray.set(-1, true).is_err());
+        assert!(bitarray.get(-1).is_err());
+    }
+
+    #[test]
+    fn test_bitarray_from_bytes_to_bytes() {
+        let bitarray_size: i64 = 10;
+
+        let bitarray: BitArray = BitArray::from_bytes(bitarray_size, &[0xFF, 0xFF]).unwrap();
+
+        // Bits beyond the bitarray size must be masked out of the last byte.
+        assert_eq!(bitarray.to_bytes(), &[0xFF, 0b0000_0011]);
+    }
+
+    #[test]
+    fn test_bitarray_from_bytes_with_error() {
+        let bitarray_size: i64 = 10;
+
+        let success: bool = match BitArray::from_bytes(bitarray_size, &[0xFF]) {
+            Ok(_) => false,
+            Err(err) => {
+                err.bitarray_size == 10
+                    && err.expected_bytes_count == 2
+                    && err.given_bytes_count == 1
+            }
+        };
+
+        assert!(success);
+    }
+
+    #[test]
+    fn test_bitarray_from_bytes_with_size_multiple_of_8() {
+        let bitarray_size: i64 = 8;
+
+        let bitarray: BitArray = BitArray::from_bytes(bitarray_size, &[0xFF, 0xFF]).unwrap();
+
+        // The trailing byte is pure padding beyond a size that is an exact multiple of 8,
+        // so it must be zeroed entirely rather than kept as 0xFF.
+        assert_eq!(bitarray.to_bytes(), &[0xFF, 0x00]);
+        assert_eq!(bitarray.count_ones(), 8);
+    }
+
+    #[test]
+    fn test_bitarray_fill_clear_count_ones() {
+        let bitarray_size: i64 = 10;
+
+        let mut bitarray: BitArray = BitArray::new(bitarray_size);
+
+        bitarray.fill();
+        assert_eq!(bitarray.count_ones(), 10);
+
+        bitarray.clear();
+        assert_eq!(bitarray.count_ones(), 0);
+    }
+
+    #[test]
+    fn test_bitarray_and_or_xor_not() {
+        let bitarray_size: i64 = 8;
+
+        let mut left: BitArray = BitArray::new(bitarray_size);
+        let mut right: BitArray = BitArray::new(bitarray_size);
+
+        left.set(0, true).unwrap();
+        right.set(1, true).unwrap();
+
+        assert_eq!(left.and(&right).unwrap().count_ones(), 0);
+        assert_eq!(left.or(&right).unwrap().count_ones(), 2);
+        assert_eq!(left.xor(&right).unwrap().count_ones(), 2);
+        assert_eq!(left.not().count_ones(), 7);
+    }
+
+    #[test]
+    fn test_bitarray_combine_with_size_mismatch() {
+        let left: BitArray = BitArray::new(8);
+        let right: BitArray = BitArray::new(9);
+
+        let success: bool = match left.and(&right) {
+            Ok(_) => false,
+            Err(CombineError::SizeMismatch(err)) => err.left_size == 8 && err.right_size == 9,
+            Err(_) => false,
+        };
+
+        assert!(success);
+    }
+
+    #[test]
+    fn test_bitarray_combine_with_order_mismatch() {
+        let mut left: BitArray = BitArray::with_order(8, BitOrder::Lsb0);
+        let mut right: BitArray = BitArray::with_order(8, BitOrder::Msb0);
+
+        left.set(0, true).unwrap();
+        right.set(0, true).unwrap();
+
+        let success: bool = match left.and(&right) {
+            Ok(_) => false,
+            Err(CombineError::OrderMismatch(err)) => {
+                err.left_order == BitOrder::Lsb0 && err.right_order == BitOrder::Msb0
+            }
+            Err(_) => false,
+        };
+
+        assert!(success);
+    }
+
+    #[test]
+    fn test_bitarray_iter_ones() {
+        let bitarray_size: i64 = 10;
+
+        let mut bitarray: BitArray = BitArray::new(bitarray_size);
+
+        bitarray.set(2, true).unwrap();
+        bitarray.set(9, true).unwrap();
+
+        let positions: Vec<i64> = bitarray.iter_ones().collect();
+
+        assert_eq!(positions, vec![2, 9]);
+    }
+
+    #[test]
+    fn test_bitarray_iter_ones_msb0_ascending_within_byte() {
+        let bitarray_size: i64 = 8;
+
+        let mut bitarray: BitArray = BitArray::with_order(bitarray_size, BitOrder::Msb0);
+
+        bitarray.set(0, true).unwrap();
+        bitarray.set(7, true).unwrap();
+
+        let positions: Vec<i64> = bitarray.iter_ones().collect();
+
+        assert_eq!(positions, vec![0, 7]);
+    }
+
+    #[test]
+    fn test_bitarray_iter() {
+        let bitarray_size: i64 = 4;
+
+        let mut bitarray: BitArray = BitArray::new(bitarray_size);
+
+        bitarray.set(1, true).unwrap();
+        bitarray.set(3, true).unwrap();
+
+        let bits: Vec<bool> = bitarray.iter().collect();
+
+        assert_eq!(bits, vec![false, true, false, true]);
+    }
+
+    #[test]
+    fn test_bitarray_msb0_order() {
+        let bitarray_size: i64 = 8;
+
+        let mut bitarray: BitArray = BitArray::with_order(bitarray_size, BitOrder::Msb0);
+
+        bitarray.set(0, true).unwrap();
+
+        assert_eq!(bitarray.bit_array[0], 0b1000_0000);
+        assert!(bitarray.get(0).unwrap());
+    }
+
+    #[test]
+    fn test_bitcursor_append_and_read_bit() {
+        let mut cursor: BitCursor = BitCursor::new();
+
+        cursor.append_bit(true);
+        cursor.append_bit(false);
+        cursor.append_bit(true);
+
+        assert_eq!(cursor.read_bit(), Some(true));
+        assert_eq!(cursor.read_bit(), Some(false));
+        assert_eq!(cursor.read_bit(), Some(true));
+        assert_eq!(cursor.read_bit(), None);
+    }
+
+    #[test]
+    fn test_bitcursor_append_bits_and_reset_read_position() {
+        let mut cursor: BitCursor = BitCursor::new();
+
+        cursor.append_bits(0b101, 3);
+        cursor.append_bit(true);
+
+        assert_eq!(cursor.bitarray.size, 4);
+        assert_eq!(cursor.read_bit(), Some(true));
+        assert_eq!(cursor.read_bit(), Some(false));
+
+        cursor.reset_read_position();
+
+        assert_eq!(cursor.read_bit(), Some(true));
+    }
+
+    #[test]
+    #[should_panic(expected = "bit count 100 exceeds 64")]
+    fn test_bitcursor_append_bits_with_count_over_64() {
+        let mut cursor: BitCursor = BitCursor::new();
+
+        cursor.append_bits(0, 100);
+    }
 }